@@ -6,6 +6,11 @@ pub trait TryIterator: Sized {
 
     fn try_next(&mut self) -> Option<Result<Self::Ok, Self::Err>>;
 
+    #[inline]
+    fn try_size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
     #[inline]
     fn try_map<F, T>(self, f: F) -> TryMap<Self, F>
     where
@@ -15,6 +20,10 @@ pub trait TryIterator: Sized {
     }
 
     #[inline]
+    #[deprecated(
+        since = "0.2.0",
+        note = "despite its name this does not flatten a sub-iterator; use `try_map` followed by `and_then` for this behavior, or `try_flatten_map` to actually flatten"
+    )]
     fn try_flat_map<F, T, E>(self, f: F) -> TryFlatMap<Self, F>
     where
         F: FnMut(Self::Ok) -> Result<T, E>,
@@ -23,6 +32,19 @@ pub trait TryIterator: Sized {
         TryFlatMap { iter: self, f }
     }
 
+    #[inline]
+    fn try_flatten_map<F, U>(self, f: F) -> TryFlattenMap<Self, F, U>
+    where
+        F: FnMut(Self::Ok) -> U,
+        U: IntoIterator,
+    {
+        TryFlattenMap {
+            iter: self,
+            f,
+            current: None,
+        }
+    }
+
     #[inline]
     fn try_filter<F>(self, predicate: F) -> TryFilter<Self, F>
     where
@@ -34,6 +56,31 @@ pub trait TryIterator: Sized {
         }
     }
 
+    #[inline]
+    fn try_filter_map<F, T, E>(self, f: F) -> TryFilterMap<Self, F>
+    where
+        F: FnMut(Self::Ok) -> Result<Option<T>, E>,
+        E: From<Self::Err>,
+    {
+        TryFilterMap { iter: self, f }
+    }
+
+    #[inline]
+    fn map_err<F, E2>(self, f: F) -> MapErr<Self, F>
+    where
+        F: FnMut(Self::Err) -> E2,
+    {
+        MapErr { iter: self, f }
+    }
+
+    #[inline]
+    fn filter_err<F>(self, f: F) -> FilterErr<Self, F>
+    where
+        F: FnMut(&Self::Err) -> bool,
+    {
+        FilterErr { iter: self, f }
+    }
+
     #[inline]
     fn take_ok(self) -> TakeOk<Self> {
         TakeOk {
@@ -48,15 +95,74 @@ pub trait TryIterator: Sized {
     }
 
     #[inline]
-    fn try_collect<B>(mut self) -> Result<B, Self::Err>
+    fn try_fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Err>
     where
-        B: FromIterator<Self::Ok>,
+        F: FnMut(B, Self::Ok) -> B,
     {
-        let mut v = Vec::new();
+        let mut acc = init;
         while let Some(x) = self.try_next() {
-            v.push(x?);
+            acc = f(acc, x?);
+        }
+        Ok(acc)
+    }
+
+    #[inline]
+    fn try_count(self) -> Result<usize, Self::Err> {
+        self.try_fold(0, |count, _| count + 1)
+    }
+
+    #[inline]
+    fn try_for_each<F>(self, mut f: F) -> Result<(), Self::Err>
+    where
+        F: FnMut(Self::Ok),
+    {
+        self.try_fold((), move |(), x| f(x))
+    }
+
+    #[inline]
+    fn try_nth(mut self, mut n: usize) -> Result<Option<Self::Ok>, Self::Err> {
+        while let Some(x) = self.try_next() {
+            let x = x?;
+            if n == 0 {
+                return Ok(Some(x));
+            }
+            n -= 1;
+        }
+        Ok(None)
+    }
+
+    #[inline]
+    fn try_last(self) -> Result<Option<Self::Ok>, Self::Err> {
+        self.try_fold(None, |_, x| Some(x))
+    }
+
+    #[inline]
+    fn try_collect<B>(self) -> Result<B, Self::Err>
+    where
+        B: FromIterator<Self::Ok>,
+    {
+        let (lower, _) = self.try_size_hint();
+        let v = self.try_fold(Vec::with_capacity(lower), |mut v, x| {
+            v.push(x);
+            v
+        })?;
+        Ok(FromIterator::from_iter(v))
+    }
+
+    #[inline]
+    fn while_ok<F, R>(mut self, f: F) -> Result<R, Self::Err>
+    where
+        F: FnOnce(WhileOk<'_, Self>) -> R,
+    {
+        let mut err = None;
+        let r = f(WhileOk {
+            iter: &mut self,
+            err: &mut err,
+        });
+        match err {
+            Some(e) => Err(e),
+            None => Ok(r),
         }
-        Ok(FromIterator::from_iter(v.into_iter()))
     }
 }
 
@@ -70,6 +176,11 @@ where
     fn try_next(&mut self) -> Option<Result<T, E>> {
         self.next()
     }
+
+    #[inline]
+    fn try_size_hint(&self) -> (usize, Option<usize>) {
+        self.size_hint()
+    }
 }
 
 pub struct TryMap<I, F> {
@@ -86,6 +197,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.try_next().map(|r| r.map(&mut self.f))
     }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.try_size_hint()
+    }
 }
 
 pub struct TryFlatMap<I, F> {
@@ -105,6 +220,39 @@ where
             .try_next()
             .map(|r| r.map_err(From::from).and_then(&mut self.f))
     }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.try_size_hint()
+    }
+}
+
+pub struct TryFlattenMap<I, F, U: IntoIterator> {
+    iter: I,
+    f: F,
+    current: Option<U::IntoIter>,
+}
+
+impl<I, F, U> Iterator for TryFlattenMap<I, F, U>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> U,
+    U: IntoIterator,
+{
+    type Item = Result<U::Item, I::Err>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(cur) = &mut self.current {
+                if let Some(item) = cur.next() {
+                    return Some(Ok(item));
+                }
+                self.current = None;
+            }
+            match self.iter.try_next()? {
+                Ok(x) => self.current = Some((self.f)(x).into_iter()),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
 }
 
 pub struct TryFilter<I, F>
@@ -137,6 +285,101 @@ where
         }
         None
     }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.try_size_hint().1)
+    }
+}
+
+pub struct TryFilterMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, T, E> Iterator for TryFilterMap<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, E>,
+    E: From<I::Err>,
+{
+    type Item = Result<T, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.try_next()? {
+                Ok(x) => match (self.f)(x) {
+                    Ok(Some(t)) => return Some(Ok(t)),
+                    Ok(None) => continue,
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+pub struct MapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, E2> Iterator for MapErr<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Err) -> E2,
+{
+    type Item = Result<I::Ok, E2>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.try_next().map(|r| r.map_err(&mut self.f))
+    }
+}
+
+pub struct FilterErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Iterator for FilterErr<I, F>
+where
+    I: TryIterator,
+    F: FnMut(&I::Err) -> bool,
+{
+    type Item = Result<I::Ok, I::Err>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(x) = self.iter.try_next() {
+            match x {
+                Ok(x) => return Some(Ok(x)),
+                Err(e) => {
+                    if (self.f)(&e) {
+                        return Some(Err(e));
+                    } else {
+                        continue;
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct WhileOk<'a, I: TryIterator> {
+    iter: &'a mut I,
+    err: &'a mut Option<I::Err>,
+}
+
+impl<'a, I: TryIterator> Iterator for WhileOk<'a, I> {
+    type Item = I::Ok;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.err.is_some() {
+            return None;
+        }
+        match self.iter.try_next()? {
+            Ok(x) => Some(x),
+            Err(e) => {
+                *self.err = Some(e);
+                None
+            }
+        }
+    }
 }
 
 pub struct TakeOk<I> {
@@ -175,6 +418,10 @@ impl<I: TryIterator> Iterator for FilterOk<I> {
         }
         None
     }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.try_size_hint().1)
+    }
 }
 
 #[cfg(test)]
@@ -219,6 +466,128 @@ mod tests {
         assert_eq!(v, vec![1, 2, 4]);
     }
 
+    #[test]
+    fn try_fold() {
+        let s = vec!["1", "2", "3", "4"];
+        let sum = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .try_fold(0, |acc, n| acc + n);
+        assert_eq!(sum, Ok(10));
+    }
+
+    #[test]
+    fn try_count() {
+        let s = vec!["1", "2", "3", "4"];
+        assert_eq!(s.into_iter().map(str::parse::<i32>).try_count(), Ok(4));
+
+        let s = vec!["1", "two", "3"];
+        assert!(s.into_iter().map(str::parse::<i32>).try_count().is_err());
+    }
+
+    #[test]
+    fn try_for_each() {
+        let s = vec!["1", "2", "3"];
+        let mut seen = Vec::new();
+        let r = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .try_for_each(|n| seen.push(n));
+        assert_eq!(r, Ok(()));
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn try_nth() {
+        let s = vec!["1", "2", "3", "4"];
+        let v = s.into_iter().map(str::parse::<i32>).try_nth(2);
+        assert_eq!(v, Ok(Some(3)));
+    }
+
+    #[test]
+    fn try_last() {
+        let s = vec!["1", "2", "3", "4"];
+        let v = s.into_iter().map(str::parse::<i32>).try_last();
+        assert_eq!(v, Ok(Some(4)));
+    }
+
+    #[test]
+    fn try_filter_map() {
+        let s = vec!["1", "2", "three", "4", "skip"];
+        let v: Result<Vec<_>, String> = s
+            .into_iter()
+            .map(Ok::<_, String>)
+            .try_filter_map(|s| match s {
+                "skip" => Ok(None),
+                s => s
+                    .parse::<i32>()
+                    .map(Some)
+                    .map_err(|_| format!("bad number: {}", s)),
+            })
+            .collect();
+        assert_eq!(v, Err("bad number: three".to_string()));
+    }
+
+    #[test]
+    fn map_err() {
+        let s = vec!["1", "2", "three", "4"];
+        let v: Vec<_> = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .map_err(|e| e.to_string())
+            .collect();
+        assert!(v[2].is_err());
+    }
+
+    #[test]
+    fn filter_err() {
+        let s = vec!["1", "two", "3", "four", "5"];
+        let v: Vec<_> = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .filter_err(|_| false)
+            .collect();
+        assert_eq!(v, vec![Ok(1), Ok(3), Ok(5)]);
+    }
+
+    #[test]
+    fn try_size_hint() {
+        let s = vec!["1", "2", "3", "4"];
+        let i = s.into_iter().map(str::parse::<i32>);
+        assert_eq!(i.try_size_hint(), (4, Some(4)));
+
+        let i = i.try_map(|n| n + 1);
+        assert_eq!(i.size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn try_flatten_map() {
+        let s = vec!["1,2", "3", "four", "5,6"];
+        let v: Result<Vec<_>, _> = s
+            .into_iter()
+            .map(Ok::<_, String>)
+            .try_flatten_map(|s| s.split(',').map(|n| n.parse::<i32>().unwrap_or(-1)))
+            .collect();
+        assert_eq!(v, Ok(vec![1, 2, 3, -1, 5, 6]));
+    }
+
+    #[test]
+    fn while_ok() {
+        let s = vec!["1", "2", "3", "four", "5"];
+        let sum = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .while_ok(|it| it.sum::<i32>());
+        assert!(sum.is_err());
+
+        let s = vec!["1", "2", "3"];
+        let sum = s
+            .into_iter()
+            .map(str::parse::<i32>)
+            .while_ok(|it| it.sum::<i32>());
+        assert_eq!(sum, Ok(6));
+    }
+
     #[test]
     fn try_filter() {
         let s = vec!["1", "2", "3", "4", "5", "6"];